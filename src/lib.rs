@@ -8,10 +8,154 @@
 #![doc = include_str!("../README.md")]
 
 use std::fmt::Debug;
-use std::sync::Mutex;
+use std::sync::{Mutex, OnceLock};
 
-/// An OpenMP-style reducer that wraps a global value into a [`Mutex`],
-/// providing [shareable, cloneable copies with a local value](#method.share);
+/// The error returned by the fallible [`try_get`](Reducer::try_get) and
+/// [`try_peek`](Reducer::try_peek) when the global value's lock, or the lock
+/// of a per-thread slot used by [`share_per_thread`](Reducer::share_per_thread),
+/// has been poisoned by a worker that panicked while holding it.
+///
+/// The global value's lock can only be poisoned with the (default) `sync`
+/// feature enabled, since the `RefCell`-backed global value used otherwise
+/// has no notion of poisoning; the per-thread slots are [`Mutex`]es in both
+/// configurations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReducerError;
+
+impl std::fmt::Display for ReducerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a lock of the reducer has been poisoned")
+    }
+}
+
+impl std::error::Error for ReducerError {}
+
+/// Controls what [`SharedReducer`] and [`ThreadSharedReducer`] do, in their
+/// [`Drop`] implementation, when the global value's lock is found poisoned
+/// because an earlier worker panicked while holding it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PoisonPolicy {
+    /// Propagate the poisoning by panicking, exactly as the original
+    /// `.lock().unwrap()`-based implementation did. This is the default, so
+    /// that existing code keeps its current behavior.
+    #[default]
+    Panic,
+    /// Recover the lock via [`PoisonError::into_inner`](std::sync::PoisonError::into_inner)
+    /// and reduce the local value into it anyway, so that later workers keep
+    /// contributing to a partial result instead of poisoning the whole
+    /// reduction.
+    Recover,
+    /// Drop the local value without reducing it into the global value.
+    Skip,
+}
+
+/// Storage for [`Reducer`]'s global value.
+///
+/// With the (default) `sync` feature enabled, this is backed by a
+/// [`Mutex`], so a [`Reducer`] can be shared across threads. With the
+/// `sync` feature disabled, this is backed by a [`RefCell`](core::cell::RefCell)
+/// instead, which has no locking or poisoning overhead but restricts the
+/// [`Reducer`] to single-threaded use; [`share`](Reducer::share),
+/// [`get`](Reducer::get), and [`peek`](Reducer::peek) behave identically in
+/// both configurations.
+#[cfg(feature = "sync")]
+#[derive(Debug)]
+struct GlobalCell<G>(Mutex<G>);
+
+#[cfg(feature = "sync")]
+impl<G> GlobalCell<G> {
+    fn new(init: G) -> Self {
+        Self(Mutex::new(init))
+    }
+
+    /// Applies `f` to the global value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex is poisoned.
+    fn with_mut<R>(&self, f: impl FnOnce(&mut G) -> R) -> R {
+        f(&mut self.0.lock().unwrap())
+    }
+
+    /// Applies `f` to the global value, or returns [`ReducerError`] if the
+    /// mutex is poisoned.
+    fn try_with_mut<R>(&self, f: impl FnOnce(&mut G) -> R) -> Result<R, ReducerError> {
+        self.0.lock().map(|mut guard| f(&mut guard)).map_err(|_| ReducerError)
+    }
+
+    /// Applies `f` to the global value, recovering it via
+    /// [`PoisonError::into_inner`](std::sync::PoisonError::into_inner) if the
+    /// mutex is poisoned.
+    fn with_mut_recovering<R>(&self, f: impl FnOnce(&mut G) -> R) -> R {
+        let mut guard = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut guard)
+    }
+
+    fn into_inner(self) -> G {
+        self.0.into_inner().unwrap()
+    }
+
+    /// Consumes self and returns the inner global value, recovering it via
+    /// [`PoisonError::into_inner`](std::sync::PoisonError::into_inner) if
+    /// the mutex is poisoned.
+    fn into_inner_recovering(self) -> G {
+        self.0.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn try_into_inner(self) -> Result<G, ReducerError> {
+        self.0.into_inner().map_err(|_| ReducerError)
+    }
+}
+
+#[cfg(not(feature = "sync"))]
+#[derive(Debug)]
+struct GlobalCell<G>(core::cell::RefCell<G>);
+
+#[cfg(not(feature = "sync"))]
+impl<G> GlobalCell<G> {
+    fn new(init: G) -> Self {
+        Self(core::cell::RefCell::new(init))
+    }
+
+    fn with_mut<R>(&self, f: impl FnOnce(&mut G) -> R) -> R {
+        f(&mut self.0.borrow_mut())
+    }
+
+    fn try_with_mut<R>(&self, f: impl FnOnce(&mut G) -> R) -> Result<R, ReducerError> {
+        Ok(f(&mut self.0.borrow_mut()))
+    }
+
+    fn with_mut_recovering<R>(&self, f: impl FnOnce(&mut G) -> R) -> R {
+        f(&mut self.0.borrow_mut())
+    }
+
+    fn into_inner(self) -> G {
+        self.0.into_inner()
+    }
+
+    fn into_inner_recovering(self) -> G {
+        self.0.into_inner()
+    }
+
+    fn try_into_inner(self) -> Result<G, ReducerError> {
+        Ok(self.0.into_inner())
+    }
+}
+
+impl<G: Clone> GlobalCell<G> {
+    fn get(&self) -> G {
+        self.with_mut(|global| global.clone())
+    }
+
+    fn try_get(&self) -> Result<G, ReducerError> {
+        self.try_with_mut(|global| global.clone())
+    }
+}
+
+/// An OpenMP-style reducer that wraps a global value (in a [`Mutex`] by
+/// default, or in a [`RefCell`](core::cell::RefCell) with the `sync` feature
+/// disabled), providing [shareable, cloneable copies with a local
+/// value](#method.share);
 /// the copies will be reduced into the global value when dropped.
 ///
 /// The global value can be observed with [`peek`](Reducer::peek) if the base
@@ -84,23 +228,77 @@ use std::sync::Mutex;
 /// might perform excessive cloning if jobs are too short, you can use
 /// [`with_min_len`](https://docs.rs/rayon/latest/rayon/iter/trait.ParallelIterator.html#method.with_min_len)
 /// to reduce the amount of cloning.
-#[derive(Debug)]
-pub struct Reducer<G: Debug + Default, L: Debug + Default = G> {
-    global: Mutex<G>,
-    reduce: fn(&mut G, &L),
+pub struct Reducer<G: Debug + Default, L: Debug + Default = G, R: Fn(&mut G, &L) + Sync = ReduceFn<G, L>> {
+    global: GlobalCell<G>,
+    reduce: R,
+    /// Per-[Rayon worker thread](rayon::current_thread_index) local
+    /// accumulators used by [`share_per_thread`](Reducer::share_per_thread).
+    /// Lazily sized to [`rayon::current_num_threads`] the first time it is
+    /// needed.
+    per_thread: OnceLock<Vec<Mutex<Option<L>>>>,
+    /// What to do, when reducing a [`SharedReducer`] or
+    /// [`ThreadSharedReducer`] on drop, if the global value's lock is found
+    /// poisoned. Defaults to [`PoisonPolicy::Panic`]; set with
+    /// [`with_poison_policy`](Reducer::with_poison_policy).
+    poison_policy: PoisonPolicy,
+}
+
+/// The type of a non-capturing reduction function, as accepted by
+/// [`Reducer`] before [`new`](Reducer::new) was generalized to accept any
+/// `Fn(&mut G, &L) + Sync` closure; kept as the default third type parameter
+/// of [`Reducer`] so that existing call sites passing a plain function or
+/// non-capturing closure keep compiling unchanged.
+pub type ReduceFn<G, L = G> = fn(&mut G, &L);
+
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Debug for Reducer<G, L, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reducer")
+            .field("global", &self.global)
+            .field("per_thread", &self.per_thread)
+            .finish_non_exhaustive()
+    }
 }
 
-impl<G: Debug + Default, L: Debug + Default> Reducer<G, L> {
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Reducer<G, L, R> {
     /// Creates a new reducer with a given reduction function.
     ///
     /// The function must reduce the local value (second argument) into the
     /// global value (first argument). For the result to be deterministic, the
     /// global value must be the same regardless of the order in which the local
     /// values are reduced.
-    pub fn new(init: G, reduce: fn(global: &mut G, local: &L)) -> Self {
+    ///
+    /// `reduce` can be a plain function, or a closure capturing state (e.g. a
+    /// shared, read-only configuration); since [`SharedReducer`] and
+    /// [`ThreadSharedReducer`] are sent across threads, any captured state
+    /// must be [`Sync`].
+    pub fn new(init: G, reduce: R) -> Self {
         Reducer {
-            global: Mutex::new(init),
+            global: GlobalCell::new(init),
             reduce,
+            per_thread: OnceLock::new(),
+            poison_policy: PoisonPolicy::default(),
+        }
+    }
+
+    /// Sets the [`PoisonPolicy`] used when reducing a [`SharedReducer`] or
+    /// [`ThreadSharedReducer`] on drop.
+    pub fn with_poison_policy(mut self, poison_policy: PoisonPolicy) -> Self {
+        self.poison_policy = poison_policy;
+        self
+    }
+
+    /// Reduces `local` into the global value, following `self.poison_policy`
+    /// if the global value's lock is found poisoned; shared by
+    /// [`SharedReducer`]'s and [`ThreadSharedReducer`]'s [`Drop`]
+    /// implementations.
+    fn reduce_local_with_policy(&self, local: &L) {
+        let reduce_local = |global: &mut G| (self.reduce)(global, local);
+        match self.poison_policy {
+            PoisonPolicy::Panic => self.global.with_mut(reduce_local),
+            PoisonPolicy::Recover => self.global.with_mut_recovering(reduce_local),
+            PoisonPolicy::Skip => {
+                let _ = self.global.try_with_mut(reduce_local);
+            }
         }
     }
 
@@ -108,13 +306,52 @@ impl<G: Debug + Default, L: Debug + Default> Reducer<G, L> {
     ///
     /// The [`SharedReducer`] will be initialized with the default value of the
     /// base type.
-    pub fn share(&self) -> SharedReducer<G, L> {
+    pub fn share(&self) -> SharedReducer<'_, G, L, R> {
         SharedReducer {
             openmp_reducer: self,
             local: L::default(),
         }
     }
 
+    /// Returns a [`ThreadSharedReducer`] referencing this [`Reducer`].
+    ///
+    /// Unlike [`share`](Reducer::share), cloning the returned handle is free:
+    /// rather than keeping its own local value, a [`ThreadSharedReducer`]
+    /// accumulates into a slot keyed by the identity of the current
+    /// [Rayon](https://docs.rs/rayon) worker thread, so a single persistent
+    /// `L` is shared by every clone that happens to run on the same thread.
+    /// This avoids the per-job lock acquisition that
+    /// [`SharedReducer::drop`] incurs, which matters when
+    /// [`for_each_with`](https://docs.rs/rayon/latest/rayon/iter/trait.ParallelIterator.html#method.for_each_with)
+    /// clones its argument once per job rather than once per thread.
+    ///
+    /// The per-thread slots are reduced into the global value only by
+    /// [`get`](Reducer::get), [`try_get`](Reducer::try_get),
+    /// [`finish_tree`](Reducer::finish_tree), [`peek`](Reducer::peek),
+    /// [`try_peek`](Reducer::try_peek), and [`peek_tree`](Reducer::peek_tree)
+    /// (the last three reduce them into a copy of the global value).
+    ///
+    /// Because Rayon's work-stealing scheduler can run more jobs than there
+    /// are worker threads (e.g. many short
+    /// [`spawn`](https://docs.rs/rayon/latest/rayon/struct.Scope.html#method.spawn)
+    /// calls against a pool with few threads), a single slot can end up
+    /// receiving contributions from more than one logical unit of work. See
+    /// [`update`](ThreadSharedReducer::update) for what this means for the
+    /// closures you pass to it.
+    pub fn share_per_thread(&self) -> ThreadSharedReducer<'_, G, L, R> {
+        ThreadSharedReducer {
+            openmp_reducer: self,
+            fallback_local: None,
+        }
+    }
+
+    /// Returns the per-thread slots, lazily initializing them to
+    /// [`rayon::current_num_threads`] entries.
+    fn per_thread_slots(&self) -> &[Mutex<Option<L>>] {
+        self.per_thread
+            .get_or_init(|| (0..rayon::current_num_threads()).map(|_| Mutex::new(None)).collect())
+    }
+
     /// Consumes self and return the global value.
     ///
     /// Note that you cannot call this method if there are still [shared
@@ -125,14 +362,118 @@ impl<G: Debug + Default, L: Debug + Default> Reducer<G, L> {
     ///
     /// # Panics
     ///
-    /// This method will panic if the mutex is poisoned.
-    /// [`peek`](Reducer::peek).
+    /// If the [`PoisonPolicy`] is [`Panic`](PoisonPolicy::Panic) (the
+    /// default), this method will panic if the global value's mutex is
+    /// poisoned. With [`Recover`](PoisonPolicy::Recover) or
+    /// [`Skip`](PoisonPolicy::Skip), the global value is recovered via
+    /// [`PoisonError::into_inner`](std::sync::PoisonError::into_inner)
+    /// instead, matching the policy already applied by [`SharedReducer`]'s
+    /// and [`ThreadSharedReducer`]'s [`Drop`] implementations, so a poisoned
+    /// lock does not discard a partial result that [`Drop`] already
+    /// recovered.
     pub fn get(self) -> G {
-        self.global.into_inner().unwrap()
+        let Reducer {
+            global,
+            reduce,
+            per_thread,
+            poison_policy,
+        } = self;
+        let mut global = match poison_policy {
+            PoisonPolicy::Panic => global.into_inner(),
+            PoisonPolicy::Recover | PoisonPolicy::Skip => global.into_inner_recovering(),
+        };
+        if let Some(slots) = per_thread.into_inner() {
+            for slot in slots {
+                if let Some(local) = slot.into_inner().unwrap() {
+                    reduce(&mut global, &local);
+                }
+            }
+        }
+        global
+    }
+
+    /// Consumes self and return the global value, or [`ReducerError`] if the
+    /// global value's lock, or a per-thread slot's lock, has been poisoned.
+    ///
+    /// If the [`PoisonPolicy`] is [`Recover`](PoisonPolicy::Recover) or
+    /// [`Skip`](PoisonPolicy::Skip), the global value is instead recovered
+    /// via [`PoisonError::into_inner`](std::sync::PoisonError::into_inner)
+    /// and returned as `Ok`, matching the policy already applied by
+    /// [`SharedReducer`]'s and [`ThreadSharedReducer`]'s [`Drop`]
+    /// implementations, so a poisoned lock does not discard a partial
+    /// result that [`Drop`] already recovered.
+    ///
+    /// Note that you cannot call this method if there are still [shared
+    /// copies](#method.share) that have not been dropped.
+    pub fn try_get(self) -> Result<G, ReducerError> {
+        let Reducer {
+            global,
+            reduce,
+            per_thread,
+            poison_policy,
+        } = self;
+        let mut global = match poison_policy {
+            PoisonPolicy::Panic => global.try_into_inner()?,
+            PoisonPolicy::Recover | PoisonPolicy::Skip => global.into_inner_recovering(),
+        };
+        if let Some(slots) = per_thread.into_inner() {
+            for slot in slots {
+                let local = slot.into_inner().map_err(|_| ReducerError)?;
+                if let Some(local) = local {
+                    reduce(&mut global, &local);
+                }
+            }
+        }
+        Ok(global)
+    }
+
+    /// Consumes self, combining the per-thread locals into a single value via
+    /// a fixed-shape balanced binary tree before folding the result into the
+    /// global value, rather than the left-to-right fold used by
+    /// [`get`](Reducer::get).
+    ///
+    /// The pairing performed by `combine` depends only on the number of
+    /// configured per-thread slots (i.e. [`rayon::current_num_threads`] at
+    /// the time the first slot was needed), never on the locals' values or
+    /// on the order in which they were produced; for floating-point `L` in
+    /// particular, this accumulates less rounding error across slots than a
+    /// naive running sum. The result is bit-identical across runs with the
+    /// same thread count only when each per-thread slot receives the same
+    /// contributions in the same order: slots are keyed by worker thread,
+    /// so which jobs end up in which slot depends on the scheduler, and
+    /// contributions within a slot are merged in the order they arrive.
+    /// `combine` must merge its second argument into
+    /// its first in an associative way, exactly as `reduce`'s merge of a
+    /// local value into the global value is required to be.
+    ///
+    /// A per-thread slot that was never touched by
+    /// [`update`](ThreadSharedReducer::update) contributes
+    /// [`L::default`](Default::default) rather than being left out of the
+    /// tree: leaving it out would make the tree's shape depend on which
+    /// physical worker threads the scheduler happened to touch, rather than
+    /// on the thread count alone.
+    ///
+    /// Note that you cannot call this method if there are still [shared
+    /// copies](#method.share_per_thread) that have not been dropped.
+    pub fn finish_tree(self, combine: impl Fn(&mut L, &L)) -> G {
+        let Reducer {
+            global,
+            reduce,
+            per_thread,
+            poison_policy: _,
+        } = self;
+        let mut global = global.into_inner();
+        if let Some(slots) = per_thread.into_inner() {
+            let locals = slots.into_iter().map(|slot| slot.into_inner().unwrap().unwrap_or_default()).collect();
+            if let Some(result) = tree_combine(locals, &combine) {
+                reduce(&mut global, &result);
+            }
+        }
+        global
     }
 }
 
-impl<G: Debug + Default + Clone, L: Debug + Default> Reducer<G, L> {
+impl<G: Debug + Default + Clone, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Reducer<G, L, R> {
     /// Returns the current global value.
     ///
     /// Note that this method does not guarantee that all shared copies have
@@ -142,10 +483,82 @@ impl<G: Debug + Default + Clone, L: Debug + Default> Reducer<G, L> {
     ///
     /// This method will panic if the mutex is poisoned.
     pub fn peek(&self) -> G {
-        self.global.lock().unwrap().clone()
+        let mut global = self.global.get();
+        if let Some(slots) = self.per_thread.get() {
+            for slot in slots {
+                if let Some(local) = slot.lock().unwrap().as_ref() {
+                    (self.reduce)(&mut global, local);
+                }
+            }
+        }
+        global
+    }
+
+    /// Returns the current global value, or [`ReducerError`] if the global
+    /// value's lock, or a per-thread slot's lock, has been poisoned.
+    ///
+    /// Note that this method does not guarantee that all shared copies have
+    /// been dropped.
+    pub fn try_peek(&self) -> Result<G, ReducerError> {
+        let mut global = self.global.try_get()?;
+        if let Some(slots) = self.per_thread.get() {
+            for slot in slots {
+                let guard = slot.lock().map_err(|_| ReducerError)?;
+                if let Some(local) = guard.as_ref() {
+                    (self.reduce)(&mut global, local);
+                }
+            }
+        }
+        Ok(global)
+    }
+
+    /// Returns the current global value, combining the per-thread locals via
+    /// a fixed-shape balanced binary tree instead of the left-to-right fold
+    /// used by [`peek`](Reducer::peek).
+    ///
+    /// See [`finish_tree`](Reducer::finish_tree) for the guarantees
+    /// `combine` must provide and the determinism benefits of tree
+    /// combination.
+    ///
+    /// Note that this method does not guarantee that all shared copies have
+    /// been dropped.
+    pub fn peek_tree(&self, combine: impl Fn(&mut L, &L)) -> G
+    where
+        L: Clone,
+    {
+        let mut global = self.global.get();
+        if let Some(slots) = self.per_thread.get() {
+            let locals = slots.iter().map(|slot| slot.lock().unwrap().clone().unwrap_or_default()).collect();
+            if let Some(result) = tree_combine(locals, &combine) {
+                (self.reduce)(&mut global, &result);
+            }
+        }
+        global
     }
 }
 
+/// Combines `locals` pairwise, bottom-up, in a fixed-shape balanced binary
+/// tree, until a single value remains; returns [`None`] if `locals` is
+/// empty.
+///
+/// The shape of the pairing depends only on `locals.len()`, never on the
+/// values themselves or the order in which they were collected, so the
+/// result is reproducible across runs with the same number of locals.
+fn tree_combine<L>(mut locals: Vec<L>, combine: &impl Fn(&mut L, &L)) -> Option<L> {
+    while locals.len() > 1 {
+        let mut next = Vec::with_capacity(locals.len().div_ceil(2));
+        let mut iter = locals.into_iter();
+        while let Some(mut a) = iter.next() {
+            if let Some(b) = iter.next() {
+                combine(&mut a, &b);
+            }
+            next.push(a);
+        }
+        locals = next;
+    }
+    locals.into_iter().next()
+}
+
 /// A shareable copy of a [`Reducer`] containing a local value and implementing
 /// [`Clone`].
 ///
@@ -154,13 +567,20 @@ impl<G: Debug + Default + Clone, L: Debug + Default> Reducer<G, L> {
 ///
 /// When a [`SharedReducer`] is dropped, the local value will be reduced into
 /// the global value.
-#[derive(Debug)]
-pub struct SharedReducer<'a, G: Debug + Default, L: Debug + Default> {
-    openmp_reducer: &'a Reducer<G, L>,
+pub struct SharedReducer<'a, G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync = ReduceFn<G, L>> {
+    openmp_reducer: &'a Reducer<G, L, R>,
     local: L,
 }
 
-impl<G: Debug + Default, L: Debug + Default> Clone for SharedReducer<'_, G, L> {
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Debug for SharedReducer<'_, G, L, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedReducer")
+            .field("local", &self.local)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Clone for SharedReducer<'_, G, L, R> {
     /// Returns a copy sharing the same global value and
     /// with local value initialized to the default value.
     fn clone(&self) -> Self {
@@ -171,15 +591,15 @@ impl<G: Debug + Default, L: Debug + Default> Clone for SharedReducer<'_, G, L> {
     }
 }
 
-impl<G: Debug + Default, L: Debug + Default> Drop for SharedReducer<'_, G, L> {
-    /// Reduces the local value into the global value.
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Drop for SharedReducer<'_, G, L, R> {
+    /// Reduces the local value into the global value, following the
+    /// reducer's [`PoisonPolicy`] if the global value's lock is poisoned.
     fn drop(&mut self) {
-        let mut lock = self.openmp_reducer.global.lock().unwrap();
-        (self.openmp_reducer.reduce)(&mut *lock, &self.local);
+        self.openmp_reducer.reduce_local_with_policy(&self.local);
     }
 }
 
-impl<G: Debug + Default + Clone, L: Debug + Default> SharedReducer<'_, G, L> {
+impl<G: Debug + Default + Clone, L: Debug + Default, R: Fn(&mut G, &L) + Sync> SharedReducer<'_, G, L, R> {
     /// Returns the current global value.
     ///
     /// This method delegates to [`Reducer::peek`].
@@ -188,16 +608,105 @@ impl<G: Debug + Default + Clone, L: Debug + Default> SharedReducer<'_, G, L> {
     }
 }
 
-impl<G: Debug + Default, L: Debug + Default> AsRef<L> for SharedReducer<'_, G, L> {
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> AsRef<L> for SharedReducer<'_, G, L, R> {
     /// Returns a reference to the local value.
     fn as_ref(&self) -> &L {
         &self.local
     }
 }
 
-impl<G: Debug + Default, L: Debug + Default> AsMut<L> for SharedReducer<'_, G, L> {
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> AsMut<L> for SharedReducer<'_, G, L, R> {
     /// Returns a mutable reference to the local value.
     fn as_mut(&mut self) -> &mut L {
         &mut self.local
     }
 }
+
+/// A shareable copy of a [`Reducer`] that accumulates into a slot keyed by
+/// the current [Rayon](https://docs.rs/rayon) worker thread, rather than
+/// keeping its own local value.
+///
+/// Cloning a [`ThreadSharedReducer`] is free, as it just copies the
+/// reference to the [`Reducer`]; the actual accumulation happens in
+/// [`update`](ThreadSharedReducer::update), which resolves the slot to use
+/// via [`rayon::current_thread_index`] on every call, so clones that end up
+/// running on the same worker thread transparently share the same local
+/// value. This also means a slot can receive more than one contribution
+/// before it is reduced into the global value; see
+/// [`update`](ThreadSharedReducer::update) for what this requires of the
+/// closures you pass to it.
+///
+/// If no Rayon worker-thread index is available (e.g., the code is not
+/// running inside a Rayon thread pool), [`update`](ThreadSharedReducer::update)
+/// falls back to a local value owned by this handle, which is reduced into
+/// the global value when the handle is dropped, exactly like
+/// [`SharedReducer`].
+///
+/// The per-thread slots themselves are reduced into the global value by
+/// [`Reducer::get`] and [`Reducer::peek`], not by dropping this handle.
+pub struct ThreadSharedReducer<'a, G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync = ReduceFn<G, L>> {
+    openmp_reducer: &'a Reducer<G, L, R>,
+    fallback_local: Option<L>,
+}
+
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Debug for ThreadSharedReducer<'_, G, L, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThreadSharedReducer")
+            .field("fallback_local", &self.fallback_local)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> ThreadSharedReducer<'_, G, L, R> {
+    /// Applies `f` to the local value for the current Rayon worker thread,
+    /// initializing it with [`L::default`](Default::default) on first use.
+    ///
+    /// # `f` must merge, not overwrite
+    ///
+    /// The slot `f` is applied to is keyed by
+    /// [`rayon::current_thread_index`], not by the identity of the logical
+    /// unit of work calling `update`. Since the scheduler is free to run
+    /// several such units back-to-back on the same worker thread, `f` may be
+    /// applied more than once to the same local value before it is reduced
+    /// into the global value. Write `f` so that it merges its contribution
+    /// into the existing local value (e.g. `|local| *local += 1`), never so
+    /// that it unconditionally overwrites it (e.g. `|local| *local = 1`),
+    /// or contributions from an earlier unit of work sharing the slot will
+    /// be silently lost.
+    pub fn update<Ret>(&mut self, f: impl FnOnce(&mut L) -> Ret) -> Ret {
+        match rayon::current_thread_index() {
+            Some(index) => {
+                let slots = self.openmp_reducer.per_thread_slots();
+                let mut slot = slots[index].lock().unwrap();
+                f(slot.get_or_insert_with(L::default))
+            }
+            None => f(self.fallback_local.get_or_insert_with(L::default)),
+        }
+    }
+}
+
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Clone for ThreadSharedReducer<'_, G, L, R> {
+    /// Returns a copy sharing the same per-thread slots, with no fallback
+    /// local value of its own until [`update`](ThreadSharedReducer::update)
+    /// needs one.
+    fn clone(&self) -> Self {
+        ThreadSharedReducer {
+            openmp_reducer: self.openmp_reducer,
+            fallback_local: None,
+        }
+    }
+}
+
+impl<G: Debug + Default, L: Debug + Default, R: Fn(&mut G, &L) + Sync> Drop for ThreadSharedReducer<'_, G, L, R> {
+    /// Reduces the fallback local value, if [`update`](ThreadSharedReducer::update)
+    /// ever created one, into the global value, following the reducer's
+    /// [`PoisonPolicy`] if the global value's lock is poisoned; a handle
+    /// that only used per-thread slots does not touch the global value. The
+    /// per-thread slots are left untouched, as they are reduced by
+    /// [`Reducer::get`] and [`Reducer::peek`] instead.
+    fn drop(&mut self) {
+        if let Some(local) = &self.fallback_local {
+            self.openmp_reducer.reduce_local_with_policy(local);
+        }
+    }
+}
@@ -5,7 +5,7 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use openmp_reducer::Reducer;
+use openmp_reducer::{PoisonPolicy, Reducer};
 
 #[test]
 fn test() {
@@ -43,6 +43,85 @@ fn test_two_types() {
     assert_eq!(reducer.get(), 8);
 }
 
+#[test]
+fn test_share_per_thread() {
+    let reducer = Reducer::new(3, |global, local| *global += *local);
+    {
+        let mut shared0 = reducer.share_per_thread();
+        let mut shared1 = shared0.clone();
+
+        shared0.update(|local| *local = 5);
+        shared1.update(|local| *local = 10);
+    }
+
+    assert_eq!(reducer.get(), 18);
+}
+
+// Spawns real Rayon worker threads sharing `&reducer`, which requires
+// `Reducer: Sync`; with the `sync` feature disabled, the global value is
+// backed by a `RefCell` and the reducer is no longer `Sync`.
+#[cfg(feature = "sync")]
+#[test]
+fn test_share_per_thread_pool() {
+    let reducer = Reducer::new(0i64, |global, local: &i64| *global += *local);
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+    pool.scope(|s| {
+        for i in 1..=8 {
+            let mut shared = reducer.share_per_thread();
+            s.spawn(move |_| {
+                shared.update(|local| *local += i);
+            });
+        }
+    });
+
+    // With only 2 worker threads and 8 spawned jobs, several jobs must share
+    // a slot; the additive closure above must still account for every one
+    // of them.
+    assert_eq!(reducer.get(), 36);
+}
+
+// See the comment on `test_share_per_thread_pool` above: this also spawns
+// real Rayon worker threads sharing `&reducer`, so it needs `Reducer: Sync`.
+#[cfg(feature = "sync")]
+#[test]
+fn test_finish_tree() {
+    let reducer = Reducer::new(0i64, |global, local: &i64| *global += *local);
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+    pool.scope(|s| {
+        for i in 0..4 {
+            let mut shared = reducer.share_per_thread();
+            s.spawn(move |_| {
+                shared.update(|local| *local += i + 1);
+            });
+        }
+    });
+
+    assert_eq!(reducer.finish_tree(|a, b| *a += *b), 10);
+}
+
+#[test]
+fn test_get_recovers_from_poison() {
+    let reducer = Reducer::new(0i32, |global: &mut i32, local: &i32| {
+        assert_ne!(*local, -1, "boom");
+        *global += *local;
+    })
+    .with_poison_policy(PoisonPolicy::Recover);
+
+    let poisoning = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut shared = reducer.share();
+        *shared.as_mut() = -1;
+        drop(shared);
+    }));
+    assert!(poisoning.is_err());
+
+    {
+        let mut shared = reducer.share();
+        *shared.as_mut() = 7;
+    }
+
+    assert_eq!(reducer.try_get(), Ok(7));
+}
+
 #[test]
 fn test_peek_count() {
     let reducer = Reducer::new(3, |global, local| *global += *local);